@@ -0,0 +1,62 @@
+//! Proxy-group enumeration and latency testing against the clash/mihomo
+//! RESTful API (`GET /proxies`, `GET /proxies/{name}/delay`,
+//! `PUT /proxies/{name}`).
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{ClashUtil, Resp};
+
+/// A single proxy or proxy-group entry, as returned by `GET /proxies`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProxyNode {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The currently active member, for `Selector`/`URLTest`-type groups.
+    #[serde(default)]
+    pub now: Option<String>,
+    /// Member node names, for group-type entries. Empty for leaf proxies.
+    #[serde(default)]
+    pub all: Vec<String>,
+}
+
+/// `GET /proxies` response: every proxy/group the core currently knows
+/// about, keyed by name.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Proxies {
+    pub proxies: HashMap<String, ProxyNode>,
+}
+
+#[derive(Deserialize)]
+struct DelayResp {
+    delay: u32,
+}
+
+impl ClashUtil {
+    /// Lists every proxy and proxy-group known to the running core.
+    pub fn get_proxies(&self) -> Result<Proxies, Resp> {
+        self.get_as("/proxies")
+    }
+
+    /// Runs a delay test for `node` against `test_url`, bounded by
+    /// `timeout_ms`. A timeout or core-side failure comes back as `Err`
+    /// rather than panicking, so the caller can show a per-node failure.
+    pub fn test_delay(&self, node: &str, test_url: &str, timeout_ms: u32) -> Result<u32, Resp> {
+        let path = format!(
+            "/proxies/{}/delay?url={}&timeout={}",
+            urlencoding::encode(node),
+            urlencoding::encode(test_url),
+            timeout_ms
+        );
+        self.get_as::<DelayResp>(&path).map(|r| r.delay)
+    }
+
+    /// Selects `node` as the active member of the `Selector`-type group
+    /// `group`.
+    pub fn select_proxy(&self, group: &str, node: &str) -> Result<(), Resp> {
+        let path = format!("/proxies/{}", urlencoding::encode(group));
+        self.put_json(&path, &serde_json::json!({ "name": node }))
+    }
+}
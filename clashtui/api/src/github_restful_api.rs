@@ -0,0 +1,289 @@
+//! GitHub-backed self-update checks for the clash/mihomo core binary:
+//! query the latest release, compare it against the running version, and
+//! download the matching asset for the host platform.
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    prerelease: bool,
+    assets: Vec<Asset>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Asset {
+    pub name: String,
+    pub browser_download_url: String,
+}
+
+/// A release newer than the currently running core, with an asset that
+/// matches the host platform.
+#[derive(Debug, Clone)]
+pub struct UpdateInfo {
+    pub tag: String,
+    pub asset: Asset,
+}
+
+/// Talks to the GitHub Releases API for a single `owner/repo`.
+pub struct GithubApi {
+    client: reqwest::blocking::Client,
+    repo: String,
+}
+
+impl GithubApi {
+    pub fn new(repo: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::blocking::Client::new(),
+            repo: repo.into(),
+        }
+    }
+
+    /// Finds the newest release (skipping pre-releases unless
+    /// `include_prerelease`) and, if its tag is newer than `running_version`,
+    /// the asset it ships for the host OS/arch. `Ok(None)` means already
+    /// up to date. If that newest release has no asset for the host, this
+    /// returns `Err`; older releases are not searched.
+    pub fn check_update(
+        &self,
+        running_version: &str,
+        include_prerelease: bool,
+    ) -> Result<Option<UpdateInfo>, String> {
+        let url = format!("https://api.github.com/repos/{}/releases", self.repo);
+        let releases: Vec<Release> = self
+            .client
+            .get(&url)
+            .header("User-Agent", "clashtui")
+            .send()
+            .map_err(|e| e.to_string())?
+            .json()
+            .map_err(|e| e.to_string())?;
+
+        let running = parse_semver(running_version)
+            .ok_or_else(|| format!("could not parse running version `{running_version}`"))?;
+
+        let latest = releases
+            .into_iter()
+            .filter(|r| include_prerelease || !r.prerelease)
+            .filter_map(|r| parse_semver(&r.tag_name).map(|v| (v, r)))
+            .max_by_key(|(v, _)| *v);
+
+        let Some((latest_version, release)) = latest else {
+            return Ok(None);
+        };
+        if latest_version <= running {
+            return Ok(None);
+        }
+
+        let asset = release
+            .assets
+            .into_iter()
+            .find(|a| asset_matches_host(&a.name))
+            .ok_or_else(|| format!("no asset for this platform in {}", release.tag_name))?;
+
+        Ok(Some(UpdateInfo {
+            tag: release.tag_name,
+            asset,
+        }))
+    }
+
+    /// Downloads `asset` to `dest` so the caller can swap it into place.
+    pub fn download_asset(&self, asset: &Asset, dest: &std::path::Path) -> Result<(), String> {
+        let mut resp = self
+            .client
+            .get(&asset.browser_download_url)
+            .header("User-Agent", "clashtui")
+            .send()
+            .map_err(|e| e.to_string())?;
+        let mut file = std::fs::File::create(dest).map_err(|e| e.to_string())?;
+        resp.copy_to(&mut file).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// The full self-update path the (not-yet-added) `ClashTuiUtil` wrapper
+    /// method of the same name is meant to call: check for a newer release,
+    /// download its asset, decompress it, and atomically swap it in.
+    /// `Ok(None)` means the running core was already current, so nothing
+    /// was downloaded. Release assets are compressed (`.gz` on Linux/macOS,
+    /// `.zip` on Windows), so the raw download is never executable on its
+    /// own — it's decompressed into a second temp file before the swap.
+    pub fn check_and_apply_core_update(
+        &self,
+        core_path: &std::path::Path,
+        running_version: &str,
+        include_prerelease: bool,
+    ) -> Result<Option<String>, String> {
+        let Some(update) = self.check_update(running_version, include_prerelease)? else {
+            return Ok(None);
+        };
+
+        let download_path = core_path.with_extension("download");
+        self.download_asset(&update.asset, &download_path)?;
+        let tmp_path = core_path.with_extension("update");
+        let decompressed = decompress_asset(&download_path, &update.asset.name, &tmp_path);
+        let _ = std::fs::remove_file(&download_path);
+        decompressed?;
+        set_executable(&tmp_path)?;
+        std::fs::rename(&tmp_path, core_path).map_err(|e| e.to_string())?;
+
+        Ok(Some(update.tag))
+    }
+}
+
+/// Decompresses `archive_path` (the raw download, named after `asset_name`)
+/// into `out_path`. `.gz` is a bare gzip-compressed binary, `.zip` wraps one
+/// or more entries and uses the first non-directory one; anything else is
+/// assumed to already be a plain binary. Either way, the result is checked
+/// to be non-empty as a minimal sanity check before it's treated as the new
+/// core binary.
+fn decompress_asset(
+    archive_path: &std::path::Path,
+    asset_name: &str,
+    out_path: &std::path::Path,
+) -> Result<(), String> {
+    let lower = asset_name.to_ascii_lowercase();
+    if lower.ends_with(".gz") {
+        let file = std::fs::File::open(archive_path).map_err(|e| e.to_string())?;
+        let mut decoder = flate2::read::GzDecoder::new(file);
+        let mut out = std::fs::File::create(out_path).map_err(|e| e.to_string())?;
+        std::io::copy(&mut decoder, &mut out).map_err(|e| e.to_string())?;
+    } else if lower.ends_with(".zip") {
+        let file = std::fs::File::open(archive_path).map_err(|e| e.to_string())?;
+        let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+        let mut wrote = false;
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i).map_err(|e| e.to_string())?;
+            if entry.is_dir() {
+                continue;
+            }
+            let mut out = std::fs::File::create(out_path).map_err(|e| e.to_string())?;
+            std::io::copy(&mut entry, &mut out).map_err(|e| e.to_string())?;
+            wrote = true;
+            break;
+        }
+        if !wrote {
+            return Err(format!("update archive `{asset_name}` contained no files"));
+        }
+    } else {
+        std::fs::copy(archive_path, out_path).map_err(|e| e.to_string())?;
+    }
+
+    let size = std::fs::metadata(out_path)
+        .map_err(|e| e.to_string())?
+        .len();
+    if size == 0 {
+        return Err(format!("decompressed `{asset_name}` is empty"));
+    }
+    Ok(())
+}
+
+/// Marks `path` as executable on Unix; a no-op elsewhere since Windows has
+/// no equivalent permission bit.
+#[cfg(unix)]
+fn set_executable(path: &std::path::Path) -> Result<(), String> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut perms = std::fs::metadata(path)
+        .map_err(|e| e.to_string())?
+        .permissions();
+    perms.set_mode(perms.mode() | 0o111);
+    std::fs::set_permissions(path, perms).map_err(|e| e.to_string())
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &std::path::Path) -> Result<(), String> {
+    Ok(())
+}
+
+/// Parses a `v`-prefixed or bare semantic version tag (`v1.2.3`, `1.2.3-beta`)
+/// into a comparable `(major, minor, patch)` tuple. Anything with a
+/// non-numeric leading component returns `None` rather than panicking.
+fn parse_semver(tag: &str) -> Option<(u64, u64, u64)> {
+    let trimmed = tag.strip_prefix('v').unwrap_or(tag);
+    let mut parts = trimmed.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().unwrap_or("0").parse().ok()?;
+    let patch = parts
+        .next()
+        .unwrap_or("0")
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()?;
+    Some((major, minor, patch))
+}
+
+fn asset_matches_host(name: &str) -> bool {
+    let os_token = if cfg!(target_os = "windows") {
+        "windows"
+    } else if cfg!(target_os = "macos") {
+        "darwin"
+    } else {
+        "linux"
+    };
+    let arch_token = if cfg!(target_arch = "x86_64") {
+        "amd64"
+    } else if cfg!(target_arch = "aarch64") {
+        "arm64"
+    } else {
+        "unknown"
+    };
+    let lower = name.to_ascii_lowercase();
+    lower.contains(os_token) && lower.contains(arch_token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_semver_v_prefixed() {
+        assert_eq!(parse_semver("v1.2.3"), Some((1, 2, 3)));
+    }
+
+    #[test]
+    fn parse_semver_bare() {
+        assert_eq!(parse_semver("1.2.3"), Some((1, 2, 3)));
+    }
+
+    #[test]
+    fn parse_semver_missing_components_default_to_zero() {
+        assert_eq!(parse_semver("v2"), Some((2, 0, 0)));
+        assert_eq!(parse_semver("v2.5"), Some((2, 5, 0)));
+    }
+
+    #[test]
+    fn parse_semver_strips_prerelease_suffix() {
+        assert_eq!(parse_semver("1.2.3-beta"), Some((1, 2, 3)));
+        assert_eq!(parse_semver("v1.2.3-rc1"), Some((1, 2, 3)));
+    }
+
+    #[test]
+    fn parse_semver_rejects_non_numeric_major() {
+        assert_eq!(parse_semver("latest"), None);
+        assert_eq!(parse_semver(""), None);
+    }
+
+    #[test]
+    fn asset_matches_host_current_platform() {
+        let os_token = if cfg!(target_os = "windows") {
+            "windows"
+        } else if cfg!(target_os = "macos") {
+            "darwin"
+        } else {
+            "linux"
+        };
+        let arch_token = if cfg!(target_arch = "x86_64") {
+            "amd64"
+        } else {
+            "arm64"
+        };
+        assert!(asset_matches_host(&format!(
+            "clashtui-{os_token}-{arch_token}.tar.gz"
+        )));
+    }
+
+    #[test]
+    fn asset_matches_host_rejects_other_platforms() {
+        assert!(!asset_matches_host("clashtui-freebsd-riscv.tar.gz"));
+    }
+}
@@ -1,11 +1,18 @@
 mod clash;
 mod config;
-#[cfg(target_feature = "deprecated")]
+#[cfg(feature = "deprecated")]
 mod dl_mihomo;
-#[cfg(target_feature = "github_api")]
+#[cfg(feature = "github_api")]
 mod github_restful_api;
 
 pub use clash::{ClashUtil, Resp, UrlType, UrlItem, ProfileSectionType};
+pub use clash::proxies::{Proxies, ProxyNode};
 pub use config::{ClashConfig, Mode, TunStack};
-#[cfg(target_feature = "github_api")]
-pub use github_restful_api::GithubApi;
+#[cfg(feature = "github_api")]
+pub use github_restful_api::{Asset, GithubApi, UpdateInfo};
+
+// NOTE: `target_feature` (replaced above; the `github_api` cfg sites added
+// in `clashtui/src` use `feature` as well) is for CPU target features, not Cargo
+// features, so these never actually compiled in. Using `feature = "..."`
+// still needs `github_api`/`deprecated` declared under `[features]` in this
+// crate's (and `clashtui`'s) `Cargo.toml`, which doesn't exist in this tree.
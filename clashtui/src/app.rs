@@ -2,10 +2,12 @@ use core::cell::{OnceCell, RefCell};
 use std::{path::PathBuf, rc::Rc};
 
 use crate::msgpopup_methods;
+use crate::scheduler::{Scheduler, SharedScheduler, Task, TaskResult};
+use crate::watcher::ConfigWatcher;
 use crate::tui::{
     tabs::{ClashSrvCtlTab, ProfileTab, TabEvent, Tabs},
     tools,
-    utils::{HelpPopUp, InfoPopUp, Keys},
+    utils::{CmdPopUp, HelpPopUp, InfoPopUp, KeyConfig, Keys, PaletteCommand, SharedKeyConfig},
     widgets::MsgPopup,
     EventState, StatusBar, TabBar, Theme, Visibility,
 };
@@ -19,10 +21,14 @@ pub struct App {
     pub should_quit: bool,
     help_popup: OnceCell<Box<HelpPopUp>>,
     info_popup: InfoPopUp,
+    cmd_popup: OnceCell<Box<CmdPopUp>>,
     msgpopup: MsgPopup,
 
     clashtui_util: SharedClashTuiUtil,
     statusbar: StatusBar,
+    scheduler: SharedScheduler,
+    key_config: SharedKeyConfig,
+    watcher: Option<ConfigWatcher>,
 }
 
 impl App {
@@ -31,7 +37,7 @@ impl App {
         let _ = std::fs::remove_file(clashtui_config_dir.join("clashtui.log")); // auto rm old log for debug
         setup_logging(clashtui_config_dir.join("clashtui.log").to_str().unwrap());
 
-        let (util, err_track) =
+        let (util, mut err_track) =
             ClashTuiUtil::new(clashtui_config_dir, !flags.contains(Flag::FirstInit));
         if flags.contains(Flag::UpdateOnly) {
             log::info!("Cron Mode!");
@@ -54,6 +60,7 @@ impl App {
         let clashtui_state =
             SharedClashTuiState::new(RefCell::new(State::new(Rc::clone(&clashtui_util))));
         let _ = Theme::load(None).map_err(|e| log::error!("Loading Theme:{e}"));
+        let scheduler: SharedScheduler = Rc::new(Scheduler::new(&clashtui_util));
 
         let tabs: Vec<Tabs> = vec![
             Tabs::Profile(ProfileTab::new(
@@ -63,21 +70,38 @@ impl App {
             Tabs::ClashSrvCtl(ClashSrvCtlTab::new(
                 clashtui_util.clone(),
                 clashtui_state.clone(),
+                scheduler.clone(),
             )),
         ]; // Init the tabs
         let tabbar = TabBar::new(tabs.iter().map(|v| v.to_string()).collect());
-        let statusbar = StatusBar::new(Rc::clone(&clashtui_state));
+        // NOTE: `StatusBar` itself (not part of this change set) still needs
+        // to store this handle and render `Scheduler::in_flight()`; this
+        // call site only threads the scheduler through so it can.
+        let statusbar = StatusBar::new(Rc::clone(&clashtui_state), scheduler.clone());
         let info_popup = InfoPopUp::with_items(&clashtui_util.clash_version());
+        let (key_config, key_cfg_errors) = KeyConfig::from_cfg(&clashtui_util.tui_cfg.keys);
+        let key_config = Rc::new(key_config);
+        err_track.extend(key_cfg_errors);
+        let watcher = ConfigWatcher::new(&[
+            clashtui_util.clashtui_dir.clone(),
+            PathBuf::from(&clashtui_util.tui_cfg.clash_cfg_dir),
+        ])
+        .map_err(|e| log::error!("Failed to start config watcher: {e}"))
+        .ok();
 
         let app = Self {
             tabbar,
             should_quit: false,
             help_popup: Default::default(),
             info_popup,
+            cmd_popup: Default::default(),
             msgpopup: Default::default(),
             statusbar,
             clashtui_util,
             tabs,
+            scheduler,
+            key_config,
+            watcher,
         };
 
         (Some(app), err_track)
@@ -93,6 +117,16 @@ impl App {
         if event_state.is_notconsumed() {
             event_state = self.info_popup.event(ev)?;
         }
+        if event_state.is_notconsumed() {
+            event_state = self
+                .cmd_popup
+                .get_mut()
+                .and_then(|v| v.event(ev).ok())
+                .unwrap_or(EventState::NotConsumed);
+            if let Some(action) = self.cmd_popup.get_mut().and_then(|v| v.take_selected()) {
+                self.dispatch_action(action);
+            }
+        }
         // ## Tab Popups
         let mut iter = self.tabs.iter_mut().map(|v| match v {
             Tabs::Profile(tab) => tab.popup_event(ev),
@@ -121,52 +155,8 @@ impl App {
             if key.kind != crossterm::event::KeyEventKind::Press {
                 return Ok(EventState::NotConsumed);
             }
-            event_state = match key.code.into() {
-                Keys::AppQuit => {
-                    self.should_quit = true;
-                    EventState::WorkDone
-                }
-                Keys::AppHelp => {
-                    self.help_popup.get_or_init(|| Box::new(HelpPopUp::new()));
-                    self.help_popup.get_mut().unwrap().show();
-                    EventState::WorkDone
-                }
-                Keys::AppInfo => {
-                    self.info_popup.show();
-                    EventState::WorkDone
-                }
-                Keys::ClashConfig => {
-                    let _ = self
-                        .clashtui_util
-                        .open_dir(self.clashtui_util.clashtui_dir.as_path())
-                        .map_err(|e| log::error!("ODIR: {}", e));
-                    EventState::WorkDone
-                }
-                Keys::AppConfig => {
-                    let _ = self
-                        .clashtui_util
-                        .open_dir(&PathBuf::from(&self.clashtui_util.tui_cfg.clash_cfg_dir))
-                        .map_err(|e| log::error!("ODIR: {}", e));
-                    EventState::WorkDone
-                }
-                Keys::LogCat => {
-                    let log = self.clashtui_util.fetch_recent_logs(20);
-                    self.popup_list_msg(log);
-                    EventState::WorkDone
-                }
-                Keys::SoftRestart => {
-                    match self.clashtui_util.restart_clash() {
-                        Ok(output) => {
-                            self.popup_list_msg(output.lines().map(|line| line.trim().to_string()));
-                        }
-                        Err(err) => {
-                            self.popup_txt_msg(err.to_string());
-                        }
-                    }
-                    EventState::WorkDone
-                }
-                _ => EventState::NotConsumed,
-            };
+            let action = self.key_config.resolve(key.code, key.modifiers);
+            event_state = self.dispatch_action(action);
 
             if event_state == EventState::NotConsumed {
                 event_state = self
@@ -188,6 +178,126 @@ impl App {
 
         Ok(event_state)
     }
+    /// Runs the action bound to a [`Keys`] variant. Shared between the raw
+    /// key handler in `event` and the command palette, which dispatches the
+    /// same actions by name instead of by keypress.
+    fn dispatch_action(&mut self, action: Keys) -> EventState {
+        match action {
+            Keys::AppQuit => {
+                self.should_quit = true;
+                EventState::WorkDone
+            }
+            Keys::AppHelp => {
+                // NOTE: `HelpPopUp` (not part of this change set) still
+                // needs to store `self.key_config` and render the active
+                // bindings from it instead of its hardcoded defaults.
+                self.help_popup
+                    .get_or_init(|| Box::new(HelpPopUp::new(self.key_config.clone())));
+                self.help_popup.get_mut().unwrap().show();
+                EventState::WorkDone
+            }
+            Keys::AppInfo => {
+                self.info_popup.show();
+                EventState::WorkDone
+            }
+            Keys::CommandPalette => {
+                let commands = self.all_commands();
+                self.cmd_popup
+                    .get_or_init(|| Box::new(CmdPopUp::new(self.key_config.clone())));
+                self.cmd_popup.get_mut().unwrap().open(commands);
+                EventState::WorkDone
+            }
+            Keys::ClashConfig => {
+                let _ = self
+                    .clashtui_util
+                    .open_dir(self.clashtui_util.clashtui_dir.as_path())
+                    .map_err(|e| log::error!("ODIR: {}", e));
+                EventState::WorkDone
+            }
+            Keys::AppConfig => {
+                let _ = self
+                    .clashtui_util
+                    .open_dir(&PathBuf::from(&self.clashtui_util.tui_cfg.clash_cfg_dir))
+                    .map_err(|e| log::error!("ODIR: {}", e));
+                EventState::WorkDone
+            }
+            Keys::LogCat => {
+                let log = self.clashtui_util.fetch_recent_logs(20);
+                self.popup_list_msg(log);
+                EventState::WorkDone
+            }
+            Keys::SoftRestart => {
+                self.scheduler.dispatch(Task::RestartClash);
+                self.popup_txt_msg("Restarting clash core...".to_string());
+                EventState::WorkDone
+            }
+            #[cfg(feature = "github_api")]
+            Keys::CheckUpdate => {
+                self.scheduler.dispatch(Task::CheckCoreUpdate {
+                    include_prerelease: false,
+                });
+                self.popup_txt_msg("Checking for a core update...".to_string());
+                EventState::WorkDone
+            }
+            // Anything not handled above is a tab-owned action (profile
+            // update, proxy test/select, ...) selected from the command
+            // palette; route it to whichever tab claims it instead of
+            // dropping it, so picking a palette entry runs the same logic
+            // the tab's own key handler would. This assumes each tab grows
+            // a `dispatch_action(Keys) -> EventState` alongside `commands()`
+            // (neither tab is part of this change set).
+            action => {
+                let mut state = EventState::NotConsumed;
+                for tab in self.tabs.iter_mut() {
+                    state = match tab {
+                        Tabs::Profile(tab) => tab.dispatch_action(action),
+                        Tabs::ClashSrvCtl(tab) => tab.dispatch_action(action),
+                    };
+                    if state.is_consumed() {
+                        break;
+                    }
+                }
+                state
+            }
+        }
+    }
+
+    /// Collects every dispatchable action — `App`'s own plus each tab's —
+    /// for the command palette, so the list can't drift from what
+    /// `dispatch_action`/the tabs actually handle.
+    fn all_commands(&self) -> Vec<PaletteCommand> {
+        let mut commands = vec![
+            PaletteCommand::new("Help", "Show the keybinding help popup", Keys::AppHelp),
+            PaletteCommand::new("Info", "Show the running clash/mihomo version", Keys::AppInfo),
+            PaletteCommand::new(
+                "Open ClashTui Config",
+                "Open the clashtui config directory",
+                Keys::ClashConfig,
+            ),
+            PaletteCommand::new(
+                "Open Clash Config",
+                "Open the clash/mihomo config directory",
+                Keys::AppConfig,
+            ),
+            PaletteCommand::new("View Logs", "Show recent clash/mihomo core logs", Keys::LogCat),
+            PaletteCommand::new("Restart Core", "Restart the clash/mihomo core", Keys::SoftRestart),
+            PaletteCommand::new("Quit", "Quit ClashTui", Keys::AppQuit),
+        ];
+        #[cfg(feature = "github_api")]
+        commands.push(PaletteCommand::new(
+            "Check for Update",
+            "Check GitHub for a newer clash/mihomo core",
+            Keys::CheckUpdate,
+        ));
+        for tab in &self.tabs {
+            commands.extend(match tab {
+                Tabs::Profile(tab) => tab.commands(),
+                Tabs::ClashSrvCtl(tab) => tab.commands(),
+            });
+        }
+        commands
+    }
+
     fn late_event(&mut self) {
         self.tabs.iter_mut().for_each(|v| match v {
             Tabs::Profile(tab) => tab.late_event(),
@@ -232,10 +342,64 @@ impl App {
             v.draw(f, help_area)
         }
         self.info_popup.draw(f, help_area);
+        if let Some(v) = self.cmd_popup.get_mut() {
+            v.draw(f, help_area)
+        }
         self.msgpopup.draw(f, help_area);
     }
 
-    pub fn on_tick(&mut self) {}
+    pub fn on_tick(&mut self) {
+        if self.watcher.as_ref().is_some_and(ConfigWatcher::poll) {
+            log::debug!("external change detected, refreshing profiles");
+            // Assumes a `refresh()` method on each tab (neither tab is part
+            // of this change set); `ProxyGroupView::refresh` is the one
+            // concrete example of what it would do for `ClashSrvCtlTab`.
+            self.tabs.iter_mut().for_each(|v| match v {
+                Tabs::Profile(tab) => tab.refresh(),
+                Tabs::ClashSrvCtl(tab) => tab.refresh(),
+            });
+        }
+        for result in self.scheduler.poll() {
+            match result {
+                TaskResult::RestartClash(Ok(output)) => {
+                    self.popup_list_msg(output.lines().map(|line| line.trim().to_string()));
+                }
+                TaskResult::RestartClash(Err(err)) => self.popup_txt_msg(err),
+                TaskResult::UpdateProfile {
+                    name,
+                    result: Ok(msgs),
+                } => {
+                    self.popup_list_msg(std::iter::once(format!("Updated {name}:")).chain(msgs));
+                }
+                TaskResult::UpdateProfile {
+                    name,
+                    result: Err(err),
+                } => self.popup_txt_msg(format!("Failed to update {name}: {err}")),
+                TaskResult::FetchLogs(lines) => self.popup_list_msg(lines),
+                #[cfg(feature = "github_api")]
+                TaskResult::CoreUpdate(Ok(Some(tag))) => {
+                    self.popup_txt_msg(format!("Updated core to {tag}, restart to apply."));
+                }
+                #[cfg(feature = "github_api")]
+                TaskResult::CoreUpdate(Ok(None)) => {
+                    self.popup_txt_msg("Core is already up to date.".to_string());
+                }
+                #[cfg(feature = "github_api")]
+                TaskResult::CoreUpdate(Err(err)) => {
+                    self.popup_txt_msg(format!("Core update failed: {err}"));
+                }
+                result @ (TaskResult::Delay { .. }
+                | TaskResult::ProxySelected { .. }
+                | TaskResult::Proxies(_)) => {
+                    self.tabs.iter_mut().for_each(|v| {
+                        if let Tabs::ClashSrvCtl(tab) = v {
+                            tab.on_task_result(&result);
+                        }
+                    });
+                }
+            }
+        }
+    }
 
     fn update_tabbar(&mut self) {
         let tabname = self
@@ -0,0 +1,237 @@
+//! Background task scheduler so profile updates and clash restarts don't
+//! block the UI thread.
+
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use clashtui_api::Proxies;
+
+use crate::utils::{ClashTuiUtil, SharedClashTuiUtil};
+
+const WORKER_COUNT: usize = 2;
+const QUEUE_CAPACITY: usize = 16;
+
+/// Handle shared between `App` and any tab that needs to enqueue work
+/// (e.g. `ClashSrvCtlTab` dispatching delay tests).
+pub type SharedScheduler = Rc<Scheduler>;
+
+/// Unit of work handed to a scheduler worker.
+#[derive(Debug, Clone)]
+pub enum Task {
+    UpdateProfile { name: String, with_proxy: bool },
+    RestartClash,
+    FetchLogs { lines: usize },
+    TestDelay {
+        group: String,
+        node: String,
+        test_url: String,
+        timeout_ms: u32,
+    },
+    SelectProxy { group: String, node: String },
+    /// `GET /proxies`, run off the UI thread so constructing/refreshing
+    /// `ProxyGroupView` never blocks on the network.
+    FetchProxies,
+    #[cfg(feature = "github_api")]
+    CheckCoreUpdate { include_prerelease: bool },
+}
+
+/// Outcome of a [`Task`], delivered back to the UI thread via [`Scheduler::poll`].
+#[derive(Debug)]
+pub enum TaskResult {
+    UpdateProfile {
+        name: String,
+        result: Result<Vec<String>, String>,
+    },
+    RestartClash(Result<String, String>),
+    FetchLogs(Vec<String>),
+    Delay {
+        group: String,
+        node: String,
+        result: Result<u32, String>,
+    },
+    ProxySelected {
+        group: String,
+        node: String,
+        result: Result<(), String>,
+    },
+    Proxies(Result<Proxies, String>),
+    /// `Ok(Some(tag))` once the matching asset has been downloaded and
+    /// swapped in; `Ok(None)` means the running core was already current.
+    #[cfg(feature = "github_api")]
+    CoreUpdate(Result<Option<String>, String>),
+}
+
+/// A small pool of worker threads draining a bounded job queue, sharing an
+/// `Arc<ClashTuiUtil>` (a `Send + Sync` clone of the UI thread's `Rc`-shared
+/// handle, taken once in [`Scheduler::new`]) so profile/clash operations run
+/// off the UI thread. Results are pulled back out with [`Scheduler::poll`]
+/// from `App::on_tick`.
+pub struct Scheduler {
+    job_tx: SyncSender<Task>,
+    result_rx: Receiver<TaskResult>,
+    workers: Vec<JoinHandle<()>>,
+    /// Profile names with an `UpdateProfile` task currently queued or
+    /// running, so a second update for the same profile can't race the
+    /// first one over the same file.
+    pending_profiles: Arc<Mutex<HashSet<String>>>,
+    /// Count of tasks queued or running, for `StatusBar` to render.
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl Scheduler {
+    /// `clashtui_util` is the UI thread's `Rc`-shared handle; `Rc` is `!Send`
+    /// so it can't be captured by worker threads directly. Each worker gets
+    /// its own `Arc<ClashTuiUtil>` instead, cloned once up front from the
+    /// same underlying value — `ClashTuiUtil` is a cheap, stateless client
+    /// wrapper (paths + an HTTP client), with all actual mutable state
+    /// living in `SharedClashTuiState`, so the two handles staying in sync
+    /// is not a concern.
+    pub fn new(clashtui_util: &SharedClashTuiUtil) -> Self {
+        let (job_tx, job_rx) = sync_channel::<Task>(QUEUE_CAPACITY);
+        let job_rx = Arc::new(Mutex::new(job_rx));
+        let (result_tx, result_rx) = sync_channel::<TaskResult>(QUEUE_CAPACITY);
+        let pending_profiles = Arc::new(Mutex::new(HashSet::new()));
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let worker_util = Arc::new(ClashTuiUtil::clone(clashtui_util));
+
+        let workers = (0..WORKER_COUNT)
+            .map(|id| {
+                let job_rx = Arc::clone(&job_rx);
+                let result_tx = result_tx.clone();
+                let clashtui_util = Arc::clone(&worker_util);
+                let pending_profiles = Arc::clone(&pending_profiles);
+                let in_flight = Arc::clone(&in_flight);
+                std::thread::Builder::new()
+                    .name(format!("scheduler-worker-{id}"))
+                    .spawn(move || worker_loop(&job_rx, &result_tx, &clashtui_util, &pending_profiles, &in_flight))
+                    .expect("failed to spawn scheduler worker")
+            })
+            .collect();
+
+        Self {
+            job_tx,
+            result_rx,
+            workers,
+            pending_profiles,
+            in_flight,
+        }
+    }
+
+    /// Enqueues a task. If every worker is backed up the task is dropped and
+    /// logged rather than blocking the caller (always the UI thread). An
+    /// `UpdateProfile` for a profile that already has one queued/running is
+    /// dropped too, so the same profile file is never touched concurrently.
+    pub fn dispatch(&self, task: Task) {
+        if let Task::UpdateProfile { name, .. } = &task {
+            let mut pending = self.pending_profiles.lock().expect("pending set poisoned");
+            if !pending.insert(name.clone()) {
+                log::warn!("update for profile `{name}` already in flight, dropping duplicate");
+                return;
+            }
+        }
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        if let Err(TrySendError::Full(task)) = self.job_tx.try_send(task) {
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            if let Task::UpdateProfile { name, .. } = &task {
+                self.pending_profiles
+                    .lock()
+                    .expect("pending set poisoned")
+                    .remove(name);
+            }
+            log::warn!("scheduler queue full, dropping task: {task:?}");
+        }
+    }
+
+    /// Drains whatever task results have arrived since the last poll.
+    /// Non-blocking; intended to be called from `App::on_tick`.
+    pub fn poll(&self) -> Vec<TaskResult> {
+        self.result_rx.try_iter().collect()
+    }
+
+    /// Number of tasks currently queued or running, for `StatusBar`.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for Scheduler {
+    fn drop(&mut self) {
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn worker_loop(
+    job_rx: &Mutex<Receiver<Task>>,
+    result_tx: &SyncSender<TaskResult>,
+    clashtui_util: &Arc<ClashTuiUtil>,
+    pending_profiles: &Mutex<HashSet<String>>,
+    in_flight: &AtomicUsize,
+) {
+    loop {
+        let task = job_rx.lock().expect("scheduler queue poisoned").recv();
+        let Ok(task) = task else {
+            break;
+        };
+        let updating_profile = match &task {
+            Task::UpdateProfile { name, .. } => Some(name.clone()),
+            _ => None,
+        };
+        let result = match task {
+            Task::UpdateProfile { name, with_proxy } => TaskResult::UpdateProfile {
+                result: clashtui_util
+                    .update_local_profile(&name, with_proxy)
+                    .map(|v| v.collect())
+                    .map_err(|e| e.to_string()),
+                name,
+            },
+            Task::RestartClash => {
+                TaskResult::RestartClash(clashtui_util.restart_clash().map_err(|e| e.to_string()))
+            }
+            Task::FetchLogs { lines } => {
+                TaskResult::FetchLogs(clashtui_util.fetch_recent_logs(lines).collect())
+            }
+            Task::TestDelay {
+                group,
+                node,
+                test_url,
+                timeout_ms,
+            } => TaskResult::Delay {
+                result: clashtui_util
+                    .test_delay(&node, &test_url, timeout_ms)
+                    .map_err(|e| e.to_string()),
+                group,
+                node,
+            },
+            Task::SelectProxy { group, node } => TaskResult::ProxySelected {
+                result: clashtui_util
+                    .select_proxy(&group, &node)
+                    .map_err(|e| e.to_string()),
+                group,
+                node,
+            },
+            Task::FetchProxies => {
+                TaskResult::Proxies(clashtui_util.get_proxies().map_err(|e| e.to_string()))
+            }
+            #[cfg(feature = "github_api")]
+            Task::CheckCoreUpdate { include_prerelease } => {
+                TaskResult::CoreUpdate(clashtui_util.check_and_apply_core_update(include_prerelease))
+            }
+        };
+        if let Some(name) = updating_profile {
+            pending_profiles
+                .lock()
+                .expect("pending set poisoned")
+                .remove(&name);
+        }
+        in_flight.fetch_sub(1, Ordering::SeqCst);
+        if result_tx.send(result).is_err() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Proxy-group listing and latency testing view for [`super::ClashSrvCtlTab`].
+//! Delay tests and the proxy list itself run through the background
+//! [`Scheduler`] so the UI stays responsive; a failed or timed-out test
+//! shows as a non-fatal per-node result instead of erroring the whole view.
+//!
+//! `ClashSrvCtlTab` (not part of this change set) needs a matching
+//! `ProxyGroupView` field plus the 3-arg `ClashSrvCtlTab::new` the rest of
+//! this series assumes, and `test_delay`/`select_proxy`/`get_proxies` need
+//! to actually resolve on `SharedClashTuiUtil` (a `Deref` to the api crate's
+//! `ClashUtil`, or passthrough methods — either is outside this file).
+
+use std::collections::HashMap;
+
+use ratatui::prelude::{Constraint, Direction, Frame, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
+
+use clashtui_api::Proxies;
+
+use crate::scheduler::{SharedScheduler, Task, TaskResult};
+use crate::utils::SharedClashTuiUtil;
+
+const DEFAULT_TEST_URL: &str = "https://www.gstatic.com/generate_204";
+const DEFAULT_TIMEOUT_MS: u32 = 5000;
+
+#[derive(Default)]
+struct NodeState {
+    /// `None` until a delay test completes; `Some(Err(_))` on timeout/failure.
+    delay: Option<Result<u32, String>>,
+}
+
+pub struct ProxyGroupView {
+    clashtui_util: SharedClashTuiUtil,
+    scheduler: SharedScheduler,
+    groups: Vec<String>,
+    nodes: HashMap<String, Vec<String>>,
+    active: HashMap<String, String>,
+    node_state: HashMap<(String, String), NodeState>,
+    group_list: ListState,
+    node_list: ListState,
+}
+
+impl ProxyGroupView {
+    pub fn new(clashtui_util: SharedClashTuiUtil, scheduler: SharedScheduler) -> Self {
+        let view = Self {
+            clashtui_util,
+            scheduler,
+            groups: Vec::new(),
+            nodes: HashMap::new(),
+            active: HashMap::new(),
+            node_state: HashMap::new(),
+            group_list: ListState::default(),
+            node_list: ListState::default(),
+        };
+        view.refresh();
+        view
+    }
+
+    /// Queues a `GET /proxies` refresh through the scheduler so the initial
+    /// load (and any later one) never blocks the UI thread; the result comes
+    /// back as `TaskResult::Proxies` through `on_task_result`.
+    pub fn refresh(&self) {
+        self.scheduler.dispatch(Task::FetchProxies);
+    }
+
+    /// Rebuilds the group/node lists from a `GET /proxies` response. Only
+    /// `Selector`-type groups are kept selectable; plain proxies are hidden.
+    fn apply_proxies(&mut self, resp: Proxies) {
+        self.groups = resp
+            .proxies
+            .iter()
+            .filter(|(_, node)| node.kind == "Selector")
+            .map(|(name, _)| name.clone())
+            .collect();
+        self.groups.sort();
+        self.nodes = self
+            .groups
+            .iter()
+            .filter_map(|name| resp.proxies.get(name).map(|n| (name.clone(), n.all.clone())))
+            .collect();
+        self.active = self
+            .groups
+            .iter()
+            .filter_map(|name| {
+                resp.proxies
+                    .get(name)
+                    .and_then(|n| n.now.clone())
+                    .map(|now| (name.clone(), now))
+            })
+            .collect();
+        if self.group_list.selected().is_none() && !self.groups.is_empty() {
+            self.group_list.select(Some(0));
+        }
+    }
+
+    fn selected_group(&self) -> Option<&str> {
+        self.group_list
+            .selected()
+            .and_then(|i| self.groups.get(i))
+            .map(String::as_str)
+    }
+
+    /// Queues a delay test for the currently selected node.
+    pub fn test_selected_delay(&mut self) {
+        let Some(group) = self.selected_group().map(str::to_string) else {
+            return;
+        };
+        let Some(node) = self
+            .nodes
+            .get(&group)
+            .and_then(|nodes| self.node_list.selected().and_then(|i| nodes.get(i)))
+            .cloned()
+        else {
+            return;
+        };
+        self.node_state
+            .insert((group.clone(), node.clone()), NodeState { delay: None });
+        self.scheduler.dispatch(Task::TestDelay {
+            group,
+            node,
+            test_url: DEFAULT_TEST_URL.to_string(),
+            timeout_ms: DEFAULT_TIMEOUT_MS,
+        });
+    }
+
+    /// Selects the currently highlighted node as the active member of the
+    /// currently selected `Selector` group.
+    pub fn select_node(&mut self) {
+        let Some(group) = self.selected_group().map(str::to_string) else {
+            return;
+        };
+        let Some(node) = self
+            .nodes
+            .get(&group)
+            .and_then(|nodes| self.node_list.selected().and_then(|i| nodes.get(i)))
+            .cloned()
+        else {
+            return;
+        };
+        self.scheduler.dispatch(Task::SelectProxy { group, node });
+    }
+
+    /// Applies a [`TaskResult::Delay`]/[`TaskResult::ProxySelected`]/
+    /// [`TaskResult::Proxies`] coming back from the scheduler. Other
+    /// variants are ignored.
+    pub fn on_task_result(&mut self, result: &TaskResult) {
+        match result {
+            TaskResult::Proxies(Ok(proxies)) => self.apply_proxies(proxies.clone()),
+            TaskResult::Proxies(Err(e)) => log::error!("Failed to list proxies: {e}"),
+            TaskResult::Delay {
+                group,
+                node,
+                result,
+            } => {
+                self.node_state.insert(
+                    (group.clone(), node.clone()),
+                    NodeState {
+                        delay: Some(result.clone()),
+                    },
+                );
+            }
+            TaskResult::ProxySelected {
+                group,
+                node,
+                result: Ok(()),
+            } => {
+                self.active.insert(group.clone(), node.clone());
+            }
+            TaskResult::ProxySelected {
+                group,
+                node,
+                result: Err(e),
+            } => log::error!("Failed to select {node} in {group}: {e}"),
+            _ => {}
+        }
+    }
+
+    pub fn draw(&mut self, f: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+            .split(area);
+
+        let group_items: Vec<ListItem> = self.groups.iter().map(|g| ListItem::new(g.as_str())).collect();
+        f.render_stateful_widget(
+            List::new(group_items)
+                .block(Block::default().borders(Borders::ALL).title("Groups"))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+            chunks[0],
+            &mut self.group_list,
+        );
+
+        let Some(group) = self.selected_group().map(str::to_string) else {
+            return;
+        };
+        let node_items: Vec<ListItem> = self
+            .nodes
+            .get(&group)
+            .map(|nodes| {
+                nodes
+                    .iter()
+                    .map(|node| {
+                        let active = self.active.get(&group).is_some_and(|now| now == node);
+                        let delay = match self.node_state.get(&(group.clone(), node.clone())) {
+                            Some(NodeState { delay: Some(Ok(ms)) }) => format!("{ms}ms"),
+                            Some(NodeState { delay: Some(Err(_)) }) => "timeout".to_string(),
+                            Some(NodeState { delay: None }) => "testing...".to_string(),
+                            None => String::new(),
+                        };
+                        let marker = if active { "*" } else { " " };
+                        ListItem::new(format!("{marker} {node:<32}{delay}"))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        f.render_stateful_widget(
+            List::new(node_items)
+                .block(Block::default().borders(Borders::ALL).title("Nodes"))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+            chunks[1],
+            &mut self.node_list,
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Syntax-highlighted preview of the selected profile's YAML (and the
+//! generated clash config), shown alongside the profile list in
+//! [`super::ProfileTab`]. The pane does not track the selection itself; its
+//! content changes only when its owner calls [`PreviewPane::load`].
+//!
+//! Wiring this in needs three changes to `ProfileTab`/`profile/mod.rs`
+//! (neither part of this change set, so this type stays unused until then):
+//! add a `preview: PreviewPane` field, split `ProfileTab::draw` into a list
+//! column and this pane, and call `load`/`scroll_up`/`scroll_down` from the
+//! selection-change and scroll-key handling. This also needs `mod preview;`
+//! declared in `profile/mod.rs`, plus `syntect`/`ansi-to-tui` added as
+//! dependencies (this tree has no `Cargo.toml` to add them to).
+
+use ansi_to_tui::IntoText;
+use ratatui::prelude::{Frame, Rect};
+use ratatui::text::Text;
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+use syntect::util::as_24_bit_terminal_escaped;
+
+const THEME_NAME: &str = "base16-ocean.dark";
+
+pub struct PreviewPane {
+    content: String,
+    scroll: u16,
+    syntax_set: SyntaxSet,
+    theme_set: ThemeSet,
+}
+
+impl PreviewPane {
+    pub fn new() -> Self {
+        Self {
+            content: String::new(),
+            scroll: 0,
+            syntax_set: SyntaxSet::load_defaults_newlines(),
+            theme_set: ThemeSet::load_defaults(),
+        }
+    }
+
+    /// Loads new content to preview (profile YAML or generated config),
+    /// resetting the scroll position.
+    pub fn load(&mut self, yaml: &str) {
+        self.content = yaml.to_string();
+        self.scroll = 0;
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(1);
+    }
+
+    /// Highlights `self.content` as YAML, falling back to plain text if
+    /// highlighting or ANSI conversion fails for any reason.
+    fn highlighted_text(&self) -> Text<'static> {
+        let syntax = self
+            .syntax_set
+            .find_syntax_by_extension("yaml")
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+        let Some(theme) = self.theme_set.themes.get(THEME_NAME) else {
+            return Text::raw(self.content.clone());
+        };
+
+        let mut highlighter = HighlightLines::new(syntax, theme);
+        let mut ansi = String::new();
+        for line in self.content.lines() {
+            match highlighter.highlight_line(line, &self.syntax_set) {
+                Ok(ranges) => {
+                    ansi.push_str(&as_24_bit_terminal_escaped(&ranges[..], false));
+                    ansi.push('\n');
+                }
+                Err(e) => {
+                    log::warn!("preview: failed to highlight line: {e}");
+                    return Text::raw(self.content.clone());
+                }
+            }
+        }
+
+        ansi.into_text()
+            .unwrap_or_else(|_| Text::raw(self.content.clone()))
+    }
+
+    pub fn draw(&self, f: &mut Frame, area: Rect) {
+        let paragraph = Paragraph::new(self.highlighted_text())
+            .block(Block::default().borders(Borders::ALL).title("Preview"))
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0));
+        f.render_widget(paragraph, area);
+    }
+}
+
+impl Default for PreviewPane {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,222 @@
+//! Fuzzy command palette: lists every action `App` and the tabs know how
+//! to dispatch, along with its current keybinding, filtering as the user
+//! types. The list is collected fresh each time the palette opens via
+//! [`PaletteCommand`]s gathered from `App` and each tab's `commands()`, so
+//! it can't drift from the real dispatch table the way a hardcoded list
+//! would.
+//!
+//! This module needs `mod cmdpopup;` plus a `pub use` of [`CmdPopUp`] and
+//! [`PaletteCommand`] added to `tui/utils/mod.rs`, and assumes a
+//! `Keys::CommandPalette` variant and a `commands()` method on each tab —
+//! none of which are part of this change set.
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::prelude::{Constraint, Direction, Frame, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+
+use ui::Infailable;
+
+use super::{Keys, SharedKeyConfig};
+use crate::{EventState, Visibility};
+
+/// One palette entry: an action a user can search for by name or
+/// description. `ProfileTab`/`ClashSrvCtlTab` build their own list of these
+/// from `commands()`; `App` does the same for its global actions.
+#[derive(Debug, Clone)]
+pub struct PaletteCommand {
+    pub name: String,
+    pub description: String,
+    pub action: Keys,
+}
+
+impl PaletteCommand {
+    pub fn new(name: impl Into<String>, description: impl Into<String>, action: Keys) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            action,
+        }
+    }
+}
+
+pub struct CmdPopUp {
+    is_visible: bool,
+    input: String,
+    commands: Vec<PaletteCommand>,
+    filtered: Vec<usize>,
+    list_state: ListState,
+    key_config: SharedKeyConfig,
+    selected: Option<Keys>,
+}
+
+impl CmdPopUp {
+    pub fn new(key_config: SharedKeyConfig) -> Self {
+        Self {
+            is_visible: false,
+            input: String::new(),
+            commands: Vec::new(),
+            filtered: Vec::new(),
+            list_state: ListState::default(),
+            key_config,
+            selected: None,
+        }
+    }
+
+    /// Opens the palette with a freshly collected command list (see
+    /// `App::all_commands`), resetting any previous search/selection.
+    pub fn open(&mut self, commands: Vec<PaletteCommand>) {
+        self.commands = commands;
+        self.input.clear();
+        self.refilter();
+        self.show();
+    }
+
+    /// Returns the action chosen since the last call, clearing it.
+    pub fn take_selected(&mut self) -> Option<Keys> {
+        self.selected.take()
+    }
+
+    fn refilter(&mut self) {
+        self.filtered = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter(|(_, cmd)| fuzzy_match(&self.input, &cmd.name) || fuzzy_match(&self.input, &cmd.description))
+            .map(|(i, _)| i)
+            .collect();
+        self.list_state.select(if self.filtered.is_empty() {
+            None
+        } else {
+            Some(0)
+        });
+    }
+
+    pub fn event(&mut self, ev: &Event) -> Result<EventState, Infailable> {
+        if !self.is_visible {
+            return Ok(EventState::NotConsumed);
+        }
+        let Event::Key(key) = ev else {
+            return Ok(EventState::NotConsumed);
+        };
+        if key.kind != KeyEventKind::Press {
+            return Ok(EventState::NotConsumed);
+        }
+        match key.code {
+            KeyCode::Esc => self.hide(),
+            KeyCode::Enter => {
+                if let Some(&idx) = self
+                    .list_state
+                    .selected()
+                    .and_then(|i| self.filtered.get(i))
+                {
+                    self.selected = Some(self.commands[idx].action);
+                }
+                self.hide();
+            }
+            KeyCode::Up => {
+                let i = self.list_state.selected().unwrap_or(0);
+                self.list_state.select(Some(i.saturating_sub(1)));
+            }
+            KeyCode::Down => {
+                let i = self.list_state.selected().unwrap_or(0);
+                if i + 1 < self.filtered.len() {
+                    self.list_state.select(Some(i + 1));
+                }
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+                self.refilter();
+            }
+            KeyCode::Char(c) => {
+                self.input.push(c);
+                self.refilter();
+            }
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::WorkDone)
+    }
+
+    pub fn draw(&mut self, f: &mut Frame, area: Rect) {
+        if !self.is_visible {
+            return;
+        }
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        f.render_widget(
+            Paragraph::new(self.input.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Command")),
+            chunks[0],
+        );
+
+        let items: Vec<ListItem> = self
+            .filtered
+            .iter()
+            .map(|&idx| {
+                let cmd = &self.commands[idx];
+                let binding = self
+                    .key_config
+                    .binding_for(cmd.action)
+                    .map(|(code, modifiers)| describe_binding(code, modifiers))
+                    .unwrap_or_default();
+                ListItem::new(format!("{:<24}{:<36}{}", cmd.name, cmd.description, binding))
+            })
+            .collect();
+
+        f.render_stateful_widget(
+            List::new(items)
+                .block(Block::default().borders(Borders::ALL))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+            chunks[1],
+            &mut self.list_state,
+        );
+    }
+}
+
+fn describe_binding(code: KeyCode, modifiers: KeyModifiers) -> String {
+    let mut parts = Vec::new();
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        parts.push("Ctrl".to_string());
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        parts.push("Alt".to_string());
+    }
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        parts.push("Shift".to_string());
+    }
+    parts.push(match code {
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        other => format!("{other:?}"),
+    });
+    parts.join("+")
+}
+
+fn fuzzy_match(pattern: &str, target: &str) -> bool {
+    if pattern.is_empty() {
+        return true;
+    }
+    let mut target_chars = target.to_ascii_lowercase().chars().collect::<Vec<_>>().into_iter();
+    pattern
+        .to_ascii_lowercase()
+        .chars()
+        .all(|pc| target_chars.by_ref().any(|tc| tc == pc))
+}
+
+impl Visibility for CmdPopUp {
+    fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+    fn show(&mut self) {
+        self.is_visible = true;
+    }
+    fn hide(&mut self) {
+        self.is_visible = false;
+    }
+}
@@ -0,0 +1,216 @@
+//! User-configurable keybindings, loaded from the `[keys]` section of the
+//! TUI config so actions like [`Keys::AppQuit`] or [`Keys::SoftRestart`] can
+//! be rebound without a recompile.
+//!
+//! This module needs `mod keyconfig;` plus a `pub use` of [`KeyConfig`] and
+//! [`SharedKeyConfig`] added to `tui/utils/mod.rs`, which isn't part of this
+//! change set.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use super::Keys;
+use crate::utils::CfgError;
+
+pub type SharedKeyConfig = Rc<KeyConfig>;
+
+/// Maps each app-level [`Keys`] action listed in [`Keys::defaults`] to the
+/// key (+ modifier) combination that triggers it. Built from
+/// [`Keys::defaults`] and overridden entry-by-entry by whatever is present
+/// in the config file; actions not listed there have no binding here.
+#[derive(Debug, Clone)]
+pub struct KeyConfig {
+    bindings: HashMap<Keys, (KeyCode, KeyModifiers)>,
+}
+
+impl Default for KeyConfig {
+    fn default() -> Self {
+        Self {
+            bindings: Keys::defaults().into_iter().collect(),
+        }
+    }
+}
+
+impl KeyConfig {
+    /// Parses the `[keys]` table (`action_name = "key+spec"`), overriding the
+    /// default binding for each recognised action. Unknown action names,
+    /// unparsable specs, and bindings that collide with another action are
+    /// all reported as [`CfgError`]s rather than only logged, so they reach
+    /// `App::new`'s `err_track` the same way other config problems do.
+    pub fn from_cfg(raw: &HashMap<String, String>) -> (Self, Vec<CfgError>) {
+        let mut cfg = Self::default();
+        let mut errors = Vec::new();
+        for (name, spec) in raw {
+            let Some(action) = Keys::from_action_name(name) else {
+                errors.push(CfgError::from(format!("keybindings: unknown action `{name}`")));
+                continue;
+            };
+            let Some(binding) = parse_spec(spec) else {
+                errors.push(CfgError::from(format!(
+                    "keybindings: invalid binding `{spec}` for `{name}`"
+                )));
+                continue;
+            };
+            if let Some((other, _)) = cfg
+                .bindings
+                .iter()
+                .find(|(a, (c, m))| **a != action && *c == binding.0 && *m == binding.1)
+            {
+                errors.push(CfgError::from(format!(
+                    "keybindings: `{spec}` for `{name}` collides with existing binding for `{other:?}`"
+                )));
+                continue;
+            }
+            cfg.bindings.insert(action, binding);
+        }
+        (cfg, errors)
+    }
+
+    /// Resolves a pressed key event to the action bound to it, falling back
+    /// to [`Keys::Unknown`] so an unrecognised key is simply not consumed.
+    pub fn resolve(&self, code: KeyCode, modifiers: KeyModifiers) -> Keys {
+        self.bindings
+            .iter()
+            .find_map(|(action, (c, m))| (*c == code && *m == modifiers).then_some(*action))
+            .unwrap_or(Keys::Unknown)
+    }
+
+    /// The key combination currently bound to `action`, used by `CmdPopUp`
+    /// to show each palette entry's active binding.
+    pub fn binding_for(&self, action: Keys) -> Option<(KeyCode, KeyModifiers)> {
+        self.bindings.get(&action).copied()
+    }
+}
+
+impl Keys {
+    /// Default keybinding for every action `KeyConfig::default` seeds before
+    /// any `[keys]` override is applied. This is an inherent `impl` added
+    /// from this module rather than at `Keys`'s own definition (elsewhere,
+    /// outside this change set) — legal since it's the same crate, but it
+    /// can only add methods, not variants. `CommandPalette` and the
+    /// `#[cfg(feature = "github_api")]`-gated `CheckUpdate` below assume
+    /// those variants already exist on `Keys`; adding them is out of reach
+    /// from here.
+    pub fn defaults() -> Vec<(Keys, (KeyCode, KeyModifiers))> {
+        vec![
+            (Keys::AppQuit, (KeyCode::Char('q'), KeyModifiers::NONE)),
+            (Keys::AppHelp, (KeyCode::Char('?'), KeyModifiers::NONE)),
+            (Keys::AppInfo, (KeyCode::Char('i'), KeyModifiers::NONE)),
+            (
+                Keys::CommandPalette,
+                (KeyCode::Char('p'), KeyModifiers::CONTROL),
+            ),
+            (Keys::ClashConfig, (KeyCode::Char('c'), KeyModifiers::NONE)),
+            (Keys::AppConfig, (KeyCode::Char('g'), KeyModifiers::NONE)),
+            (Keys::LogCat, (KeyCode::Char('l'), KeyModifiers::NONE)),
+            (Keys::SoftRestart, (KeyCode::Char('r'), KeyModifiers::NONE)),
+            #[cfg(feature = "github_api")]
+            (Keys::CheckUpdate, (KeyCode::Char('u'), KeyModifiers::NONE)),
+        ]
+    }
+
+    /// Maps a `[keys]` config key (e.g. `app_quit = "ctrl+q"`) to the action
+    /// it rebinds. `None` for anything not listed here, which
+    /// `KeyConfig::from_cfg` reports as a `CfgError` instead of ignoring.
+    pub fn from_action_name(name: &str) -> Option<Keys> {
+        Some(match name {
+            "app_quit" => Keys::AppQuit,
+            "app_help" => Keys::AppHelp,
+            "app_info" => Keys::AppInfo,
+            "command_palette" => Keys::CommandPalette,
+            "clash_config" => Keys::ClashConfig,
+            "app_config" => Keys::AppConfig,
+            "log_cat" => Keys::LogCat,
+            "soft_restart" => Keys::SoftRestart,
+            #[cfg(feature = "github_api")]
+            "check_update" => Keys::CheckUpdate,
+            _ => return None,
+        })
+    }
+}
+
+fn parse_spec(spec: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut code = None;
+    for part in spec.split('+').map(str::trim).filter(|p| !p.is_empty()) {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" => modifiers |= KeyModifiers::CONTROL,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            other => code = Some(parse_code(other)?),
+        }
+    }
+    code.map(|code| (code, modifiers))
+}
+
+fn parse_code(token: &str) -> Option<KeyCode> {
+    match token {
+        "esc" | "escape" => Some(KeyCode::Esc),
+        "enter" | "return" => Some(KeyCode::Enter),
+        "tab" => Some(KeyCode::Tab),
+        "backspace" => Some(KeyCode::Backspace),
+        "space" => Some(KeyCode::Char(' ')),
+        _ if token.chars().count() == 1 => token.chars().next().map(KeyCode::Char),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_code_named_keys() {
+        assert_eq!(parse_code("esc"), Some(KeyCode::Esc));
+        assert_eq!(parse_code("escape"), Some(KeyCode::Esc));
+        assert_eq!(parse_code("enter"), Some(KeyCode::Enter));
+        assert_eq!(parse_code("tab"), Some(KeyCode::Tab));
+        assert_eq!(parse_code("backspace"), Some(KeyCode::Backspace));
+        assert_eq!(parse_code("space"), Some(KeyCode::Char(' ')));
+    }
+
+    #[test]
+    fn parse_code_single_char() {
+        assert_eq!(parse_code("q"), Some(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn parse_code_rejects_unknown_multichar_tokens() {
+        assert_eq!(parse_code("pageup"), None);
+        assert_eq!(parse_code(""), None);
+    }
+
+    #[test]
+    fn parse_spec_plain_char() {
+        assert_eq!(parse_spec("q"), Some((KeyCode::Char('q'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn parse_spec_with_single_modifier() {
+        assert_eq!(
+            parse_spec("ctrl+q"),
+            Some((KeyCode::Char('q'), KeyModifiers::CONTROL))
+        );
+    }
+
+    #[test]
+    fn parse_spec_with_multiple_modifiers_and_whitespace() {
+        assert_eq!(
+            parse_spec(" Ctrl + Shift + Q "),
+            Some((KeyCode::Char('q'), KeyModifiers::CONTROL | KeyModifiers::SHIFT))
+        );
+    }
+
+    #[test]
+    fn parse_spec_rejects_modifiers_only() {
+        assert_eq!(parse_spec("ctrl+alt"), None);
+    }
+
+    #[test]
+    fn parse_spec_rejects_garbage() {
+        assert_eq!(parse_spec(""), None);
+        assert_eq!(parse_spec("pageup"), None);
+    }
+}
@@ -0,0 +1,80 @@
+//! Filesystem watcher that debounces external edits to profiles/config
+//! directories and signals `App` to refresh, polled from `App::on_tick`.
+
+use std::path::PathBuf;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+/// Coalescing window: multi-file saves (e.g. an editor writing several
+/// profile files in a row) collapse into a single refresh signal.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches a set of directories and signals (non-blocking, polled) that
+/// something changed underneath them. `App` reacts by refreshing its
+/// profile list and `State`.
+pub struct ConfigWatcher {
+    _watcher: RecommendedWatcher,
+    rx: Receiver<()>,
+}
+
+impl ConfigWatcher {
+    pub fn new(paths: &[PathBuf]) -> notify::Result<Self> {
+        let (raw_tx, raw_rx) = channel::<()>();
+        let (tx, rx) = channel::<()>();
+
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            match res {
+                // `clashtui_dir` (one of `paths`) is also where `App` writes
+                // and rotates `clashtui.log`, so every log write would
+                // otherwise trip this watcher and force a refresh loop.
+                // Ignore an event if every touched path is a `.log` file.
+                Ok(event) if is_log_only(&event) => {}
+                Ok(_) => {
+                    let _ = raw_tx.send(());
+                }
+                Err(e) => log::warn!("watcher: {e}"),
+            }
+        })?;
+        for path in paths {
+            watcher.watch(path, RecursiveMode::Recursive)?;
+        }
+
+        std::thread::Builder::new()
+            .name("config-watcher-debounce".to_string())
+            .spawn(move || debounce_loop(&raw_rx, &tx))
+            .expect("failed to spawn watcher debounce thread");
+
+        Ok(Self {
+            _watcher: watcher,
+            rx,
+        })
+    }
+
+    /// Non-blocking; returns true if at least one coalesced change event is
+    /// pending since the last poll.
+    pub fn poll(&self) -> bool {
+        self.rx.try_iter().last().is_some()
+    }
+}
+
+fn is_log_only(event: &notify::Event) -> bool {
+    !event.paths.is_empty()
+        && event
+            .paths
+            .iter()
+            .all(|p| p.extension().and_then(|e| e.to_str()) == Some("log"))
+}
+
+fn debounce_loop(raw_rx: &Receiver<()>, tx: &Sender<()>) {
+    loop {
+        let Ok(()) = raw_rx.recv() else { break };
+        while raw_rx.recv_timeout(DEBOUNCE).is_ok() {
+            // swallow bursty events (e.g. editor writing multiple files) until quiet
+        }
+        if tx.send(()).is_err() {
+            break;
+        }
+    }
+}